@@ -2,7 +2,13 @@
 //!
 //! The Python bindings are implemented entirely in Rust using [`pyo3`].
 
-use pyo3::exceptions::{PyNotImplementedError, PyTypeError, PyValueError};
+use std::ffi::CString;
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+use pyo3::exceptions::{PyBufferError, PyNotImplementedError, PyTypeError, PyValueError};
+use pyo3::ffi;
 use pyo3::types::PyBytes;
 
 use pyo3::prelude::*;
@@ -26,6 +32,11 @@ use planetarium::{
 /// - `SpotShape((kx, ky))` -- the default shape XY stretched by `kx` and `ky` factors
 /// - `SpotShape([[xx, xy], [yx, yy]])` -- explicit transform matrix initialization
 ///
+/// Non-Gaussian optical profiles can be selected instead of the default
+/// radial profile using the `SpotShape.moffat()` and `SpotShape.airy()`
+/// static constructors, with the `scale`/`stretch`/`rotate` transforms
+/// still composable on top of the chosen profile.
+///
 /// Example usage:
 ///
 /// ```python
@@ -39,6 +50,12 @@ use planetarium::{
 ///
 /// # Stretch by 1.5 in the X direction and rotate clockwise by 45 degrees.
 /// s3 = s2.stretch(1.5, 1.0).rotate(-45.0)
+///
+/// # Create a Moffat profile spot shape with wide PSF wings.
+/// s4 = SpotShape.moffat(2.5, 3.5)
+///
+/// # Create an Airy diffraction pattern spot shape, stretched and rotated.
+/// s5 = SpotShape.airy(3.0).stretch(1.2, 1.0).rotate(30.0)
 /// ```
 #[pyclass(module = "pyplanetarium", frozen, freelist = 8)]
 struct SpotShape(RsSpotShape);
@@ -155,7 +172,20 @@ struct ImageFormat(RsImageFormat);
 /// c.draw()
 /// ```
 #[pyclass(module = "pyplanetarium")]
-struct Canvas(RsCanvas);
+struct Canvas(RsCanvas, u64);
+
+/// Read-only zero-copy view of the canvas pixel buffer
+///
+/// Implements the Python buffer protocol, so that `numpy.asarray(view)`
+/// produces a 2D array of 16-bit samples sharing memory with the canvas
+/// image instead of copying it.
+///
+/// `PixelView` objects are created by calling `Canvas.pixels()` and keep
+/// their parent `Canvas` object alive for as long as they exist.
+#[pyclass(module = "pyplanetarium")]
+struct PixelView {
+    canvas: Py<Canvas>,
+}
 
 #[pymethods]
 impl SpotShape {
@@ -194,6 +224,32 @@ impl SpotShape {
         SpotShape(self.0.rotate(phi))
     }
 
+    /// Creates a new Moffat profile spot shape.
+    ///
+    /// The Moffat profile intensity distribution is defined as
+    /// `I(r) = I0 * (1 + (r/alpha)^2)^(-beta)`, which models the heavy
+    /// wings of real optical point spread functions better than the
+    /// default radial profile. Typical seeing-limited images have
+    /// `beta` in the 2.5 to 4.7 range.
+    ///
+    /// The `scale`/`stretch`/`rotate` transforms can still be composed
+    /// on top of the resulting shape.
+    #[staticmethod]
+    fn moffat(alpha: f32, beta: f32) -> Self {
+        SpotShape(RsSpotShape::moffat(alpha, beta))
+    }
+
+    /// Creates a new Airy diffraction pattern spot shape.
+    ///
+    /// `radius` is the radius of the first dark ring of the Airy disk.
+    ///
+    /// The `scale`/`stretch`/`rotate` transforms can still be composed
+    /// on top of the resulting shape.
+    #[staticmethod]
+    fn airy(radius: f32) -> Self {
+        SpotShape(RsSpotShape::airy(radius))
+    }
+
     /// Implements `str(x)` in Python.
     fn __str__(&self) -> String {
         self.0.to_string()
@@ -354,6 +410,56 @@ fn my_to_pyerr(err: EncoderError) -> PyErr {
     }
 }
 
+/// Advances a xorshift64* pseudo-random number generator state
+/// and returns its next raw 64-bit output.
+fn xorshift64(state: &mut u64) -> u64 {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    *state = x;
+    x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+}
+
+/// Draws a uniformly distributed random value from the half-open range `[0, 1)`.
+fn next_uniform(state: &mut u64) -> f64 {
+    (xorshift64(state) >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Draws a sample from the standard normal distribution using the
+/// Box-Muller transform.
+fn next_gaussian(state: &mut u64) -> f64 {
+    let u1 = next_uniform(state).max(f64::MIN_POSITIVE);
+    let u2 = next_uniform(state);
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+/// Draws a sample from a Poisson distribution with the given `mean`
+/// using Knuth's algorithm, falling back to a Gaussian approximation
+/// for large means where the direct algorithm becomes too slow.
+fn next_poisson(state: &mut u64, mean: f64) -> f64 {
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    if mean > 100.0 {
+        return (mean + next_gaussian(state) * mean.sqrt()).max(0.0);
+    }
+
+    let l = (-mean).exp();
+    let mut k = 0u32;
+    let mut p = 1.0;
+
+    loop {
+        k += 1;
+        p *= next_uniform(state);
+        if p <= l {
+            break;
+        }
+    }
+
+    (k - 1) as f64
+}
+
 #[pymethods]
 impl Canvas {
     /// `Pixel::MAX` alias
@@ -363,7 +469,7 @@ impl Canvas {
     /// Creates a new clear canvas to render light spots on.
     #[staticmethod]
     fn new(width: u32, height: u32) -> Self {
-        Canvas(RsCanvas::new(width, height))
+        Canvas(RsCanvas::new(width, height), 0)
     }
 
     /// Creates a new light spot on the canvas.
@@ -372,6 +478,61 @@ impl Canvas {
         SpotId(id)
     }
 
+    /// Creates a batch of new light spots on the canvas from a source catalog.
+    ///
+    /// The peak intensity of each spot is calculated from the stellar magnitude
+    /// `magnitudes[i]` using the Pogson relation
+    /// `intensity = 10**(-0.4 * (magnitudes[i] - zeropoint))`, so that a star at
+    /// exactly `zeropoint` magnitude renders at the full scale peak intensity.
+    /// Intensities are not pre-clamped here: just like `add_spot()`, values
+    /// above 1.0 saturate to `Canvas.PIXEL_MAX` when the canvas is drawn.
+    ///
+    /// An optional per-star color excess `ebv` array together with the
+    /// reddening coefficient `r` applies interstellar extinction to the
+    /// effective flux, multiplying it by `10**(-0.4 * r * ebv[i])`, to
+    /// simulate dust extinction along the line of sight.
+    ///
+    /// All spots share the same spot `shape`. Returns the list of `SpotId`s
+    /// in the same order as the input arrays.
+    #[pyo3(signature = (positions, magnitudes, shape, zeropoint, ebv=None, r=3.1))]
+    fn add_catalog(
+        &mut self,
+        positions: Vec<Point>,
+        magnitudes: Vec<f32>,
+        shape: &SpotShape,
+        zeropoint: f32,
+        ebv: Option<Vec<f32>>,
+        r: f32,
+    ) -> PyResult<Vec<SpotId>> {
+        if magnitudes.len() != positions.len() {
+            return Err(PyValueError::new_err(
+                "magnitudes array must have the same length as positions",
+            ));
+        }
+        if let Some(ebv) = &ebv {
+            if ebv.len() != positions.len() {
+                return Err(PyValueError::new_err(
+                    "ebv array must have the same length as positions",
+                ));
+            }
+        }
+
+        let mut ids = Vec::with_capacity(positions.len());
+
+        for (i, position) in positions.into_iter().enumerate() {
+            let mut intensity = 10f32.powf(-0.4 * (magnitudes[i] - zeropoint));
+
+            if let Some(ebv) = &ebv {
+                intensity *= 10f32.powf(-0.4 * r * ebv[i]);
+            }
+
+            let id = self.0.add_spot(position, shape.0, intensity);
+            ids.push(SpotId(id));
+        }
+
+        Ok(ids)
+    }
+
     /// Calculates the canvas coordinates of the light spot.
     ///
     /// The canvas coordinates are calculated as the immutable spot position coordinates
@@ -416,6 +577,57 @@ impl Canvas {
         self.0.draw();
     }
 
+    /// Adds simulated detector noise to the rendered canvas image.
+    ///
+    /// Must be called after `draw()`. Each linear-light pixel value is
+    /// interpreted as `value / gain` expected photo-electrons, replaced
+    /// with a Poisson-distributed shot noise draw, perturbed by a
+    /// zero-mean Gaussian read noise of standard deviation `read_noise`
+    /// electrons, and converted back through `gain` into a pixel value
+    /// clamped to `[0, Canvas.PIXEL_MAX]`.
+    ///
+    /// The pseudo-random generator is explicitly seeded by `seed`, so
+    /// calling `add_noise()` again with the same seed on an identically
+    /// drawn canvas reproduces the exact same noisy image, which is
+    /// essential for regression tests and Monte-Carlo tracking studies.
+    fn add_noise(&mut self, read_noise: f32, gain: f32, seed: u64) -> PyResult<()> {
+        if !(gain > 0.0) {
+            return Err(PyValueError::new_err("gain must be a positive number"));
+        }
+        if !(read_noise >= 0.0) {
+            return Err(PyValueError::new_err(
+                "read_noise must be a non-negative number",
+            ));
+        }
+
+        let mut state = if seed == 0 {
+            0x9e37_79b9_7f4a_7c15
+        } else {
+            seed
+        };
+
+        for pixel in self.0.pixels_mut().iter_mut() {
+            let electrons = f64::from(*pixel) / f64::from(gain);
+            let shot = next_poisson(&mut state, electrons);
+            let read = next_gaussian(&mut state) * f64::from(read_noise);
+            let value = (shot + read) * f64::from(gain);
+
+            *pixel = value.round().clamp(0.0, f64::from(Pixel::MAX)) as Pixel;
+        }
+
+        self.1 = seed;
+
+        Ok(())
+    }
+
+    /// Returns the current detector noise generator seed.
+    ///
+    /// Can be combined with `add_noise()` to generate a reproducible
+    /// sequence of independently noisy image frames.
+    fn noise_seed(&self) -> u64 {
+        self.1
+    }
+
     /// Returns the canvas dimensions as `(width, height)`.
     fn dimensions(&self) -> (u32, u32) {
         self.0.dimensions()
@@ -478,6 +690,16 @@ impl Canvas {
         }
     }
 
+    /// Returns a read-only zero-copy view of the canvas pixel buffer.
+    ///
+    /// The returned `PixelView` object implements the Python buffer protocol,
+    /// so `numpy.asarray(canvas.pixels())` yields a 2D NumPy array of 16-bit
+    /// linear light samples sharing memory with the canvas, without copying.
+    /// The view stays valid for as long as the `Canvas` object is alive.
+    fn pixels(slf: PyRef<'_, Self>) -> PixelView {
+        PixelView { canvas: slf.into() }
+    }
+
     /// Implements `repr(x)` in Python.
     fn __repr__(&self) -> String {
         let (w, h) = self.0.dimensions();
@@ -485,6 +707,77 @@ impl Canvas {
     }
 }
 
+#[pymethods]
+impl PixelView {
+    /// Implements `repr(x)` in Python.
+    fn __repr__(&self, py: Python) -> String {
+        let (w, h) = self.canvas.borrow(py).0.dimensions();
+        format!("PixelView({w}, {h})")
+    }
+
+    unsafe fn __getbuffer__(
+        slf: PyRefMut<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("view is null"));
+        }
+        if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("pixel buffer is read-only"));
+        }
+
+        let py = slf.py();
+        let canvas = slf.canvas.borrow(py);
+        let (width, height) = canvas.0.dimensions();
+        let pixels = canvas.0.pixels();
+        let itemsize = mem::size_of::<Pixel>() as isize;
+
+        (*view).obj = ffi::Py_NewRef(slf.as_ptr());
+        (*view).buf = pixels.as_ptr() as *mut c_void;
+        (*view).len = pixels.len() as isize * itemsize;
+        (*view).readonly = 1;
+        (*view).itemsize = itemsize;
+
+        (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            CString::new("H").unwrap().into_raw()
+        } else {
+            ptr::null_mut()
+        };
+
+        (*view).ndim = 2;
+
+        (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            Box::into_raw(Box::new([height as isize, width as isize])) as *mut isize
+        } else {
+            ptr::null_mut()
+        };
+
+        (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            Box::into_raw(Box::new([width as isize * itemsize, itemsize])) as *mut isize
+        } else {
+            ptr::null_mut()
+        };
+
+        (*view).suboffsets = ptr::null_mut();
+        (*view).internal = ptr::null_mut();
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(_slf: PyRefMut<'_, Self>, view: *mut ffi::Py_buffer) {
+        if !(*view).format.is_null() {
+            drop(CString::from_raw((*view).format));
+        }
+        if !(*view).shape.is_null() {
+            drop(Box::from_raw((*view).shape as *mut [isize; 2]));
+        }
+        if !(*view).strides.is_null() {
+            drop(Box::from_raw((*view).strides as *mut [isize; 2]));
+        }
+    }
+}
+
 /// Planetarium light spot rendering library bindings for Python.
 ///
 /// This module provides a complete Python programming interface
@@ -584,6 +877,57 @@ impl Canvas {
 /// pos2x = c.spot_position(spot2)
 /// ```
 ///
+/// Detector noise simulation
+/// -------------------------
+///
+/// The `Canvas` object can simulate realistic sensor noise on top of the
+/// noiseless rendered image: Poisson shot noise plus Gaussian read noise.
+///
+/// ### Example noise simulation code
+///
+/// ```python
+/// from pyplanetarium import Canvas, SpotShape
+///
+/// c = Canvas.new(256, 256)
+///
+/// c.add_spot((128.0, 128.0), SpotShape().scale(2.5), 0.8)
+/// c.draw()
+///
+/// # Add Poisson shot noise and Gaussian read noise, seeded for reproducibility.
+/// c.add_noise(read_noise=5.0, gain=2.2, seed=42)
+///
+/// # Generate an independent noisy frame of the same scene.
+/// c.draw()
+/// c.add_noise(read_noise=5.0, gain=2.2, seed=c.noise_seed() + 1)
+/// ```
+///
+/// Star catalog rendering
+/// ----------------------
+///
+/// The `Canvas` object supports populating the canvas from an astronomical
+/// source catalog in a single batched call, instead of calling `add_spot()`
+/// for every star, which matters for catalogs of 10⁴-10⁶ sources.
+///
+/// ### Example catalog rendering code
+///
+/// ```python
+/// from pyplanetarium import Canvas, SpotShape
+///
+/// c = Canvas.new(4096, 4096)
+///
+/// shape = SpotShape().scale(2.5)
+///
+/// positions = [(103.6, 205.2), (1230.8, 3012.4)]
+/// magnitudes = [12.5, 15.1]
+///
+/// # Zeropoint magnitude renders at the canvas full scale peak intensity.
+/// spots = c.add_catalog(positions, magnitudes, shape, zeropoint=10.0)
+///
+/// # Optionally apply per-star interstellar extinction.
+/// ebv = [0.3, 0.1]
+/// spots = c.add_catalog(positions, magnitudes, shape, zeropoint=10.0, ebv=ebv, r=3.1)
+/// ```
+///
 /// Canvas image export
 /// -------------------
 ///
@@ -617,6 +961,27 @@ impl Canvas {
 /// png_16bpp_bytes = c.export_image(ImageFormat.PngLinear16Bpp)
 /// ```
 ///
+/// Zero-copy pixel buffer access
+/// ------------------------------
+///
+/// The `Canvas` object also supports exposing its pixel buffer directly
+/// through the Python buffer protocol, without copying the image data.
+/// This is useful for high frame rate video tracking applications.
+///
+/// ### Example zero-copy pixel access code
+///
+/// ```python
+/// import numpy
+/// from pyplanetarium import Canvas
+///
+/// c = Canvas.new(256, 256)
+///
+/// c.draw()
+///
+/// # Get a zero-copy 2D NumPy view of the canvas pixel buffer.
+/// image = numpy.asarray(c.pixels())
+/// ```
+///
 /// Window image export
 /// -------------------
 ///
@@ -677,6 +1042,184 @@ fn pyplanetarium(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Window>()?;
     m.add_class::<ImageFormat>()?;
     m.add_class::<Canvas>()?;
+    m.add_class::<PixelView>()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Smoke-tests the `PixelView` buffer protocol implementation:
+    /// values read back through `numpy.asarray(canvas.pixels())` must
+    /// match the canvas contents, and a writable buffer request must
+    /// be rejected with a `BufferError`.
+    #[test]
+    fn pixels_buffer_is_zero_copy_and_rejects_writable_requests() {
+        Python::with_gil(|py| {
+            if py.import("numpy").is_err() {
+                // numpy is not available in this environment; nothing to verify.
+                return;
+            }
+
+            let canvas = Py::new(py, Canvas::new(2, 2)).unwrap();
+
+            pyo3::py_run!(
+                py,
+                canvas,
+                r#"
+import io
+import numpy
+
+canvas.set_background(1000)
+canvas.draw()
+
+image = numpy.asarray(canvas.pixels())
+assert image.shape == (2, 2)
+assert image[0, 0] == 1000
+
+try:
+    io.BytesIO(b"\0\0").readinto(canvas.pixels())
+    assert False, "expected BufferError for a writable buffer request"
+except BufferError:
+    pass
+"#
+            );
+        });
+    }
+
+    /// The same seed applied to two identically drawn canvases must
+    /// produce bit-identical noisy images, which is the whole point of
+    /// making the noise generator explicitly seedable.
+    #[test]
+    fn add_noise_is_deterministic_for_a_given_seed() {
+        Python::with_gil(|py| {
+            let c1 = Py::new(py, Canvas::new(8, 8)).unwrap();
+            let c2 = Py::new(py, Canvas::new(8, 8)).unwrap();
+
+            pyo3::py_run!(
+                py,
+                c1 c2,
+                r#"
+c1.set_background(500)
+c1.draw()
+c1.add_noise(5.0, 2.0, 42)
+
+c2.set_background(500)
+c2.draw()
+c2.add_noise(5.0, 2.0, 42)
+
+assert bytes(memoryview(c1.pixels())) == bytes(memoryview(c2.pixels()))
+assert c1.noise_seed() == c2.noise_seed() == 42
+"#
+            );
+        });
+    }
+
+    /// A non-positive (or NaN) gain has no physical meaning and must be
+    /// rejected rather than silently producing garbage pixel values.
+    /// A negative (or NaN) read noise must be rejected the same way.
+    #[test]
+    fn add_noise_rejects_non_positive_gain() {
+        Python::with_gil(|py| {
+            let canvas = Py::new(py, Canvas::new(2, 2)).unwrap();
+
+            pyo3::py_run!(
+                py,
+                canvas,
+                r#"
+canvas.draw()
+
+for bad_gain in (0.0, -1.0, float("nan")):
+    try:
+        canvas.add_noise(5.0, bad_gain, 1)
+        assert False, f"expected ValueError for gain={bad_gain}"
+    except ValueError:
+        pass
+
+for bad_read_noise in (-1.0, float("nan")):
+    try:
+        canvas.add_noise(bad_read_noise, 2.0, 1)
+        assert False, f"expected ValueError for read_noise={bad_read_noise}"
+    except ValueError:
+        pass
+"#
+            );
+        });
+    }
+
+    /// A star at exactly the zeropoint magnitude must render at the
+    /// canvas full scale peak intensity (the Pogson relation's anchor
+    /// point), with no extinction applied when `ebv` is omitted.
+    #[test]
+    fn add_catalog_zeropoint_magnitude_yields_peak_intensity() {
+        Python::with_gil(|py| {
+            let canvas = Py::new(py, Canvas::new(16, 16)).unwrap();
+            let shape = Py::new(py, SpotShape(RsSpotShape::default())).unwrap();
+
+            pyo3::py_run!(
+                py,
+                canvas shape,
+                r#"
+spots = canvas.add_catalog([(8.0, 8.0)], [10.0], shape, 10.0)
+assert abs(canvas.spot_intensity(spots[0]) - 1.0) < 1e-6
+"#
+            );
+        });
+    }
+
+    /// Mismatched `magnitudes`/`ebv` array lengths are a caller error
+    /// and must raise `ValueError` rather than panicking or silently
+    /// truncating the catalog.
+    #[test]
+    fn add_catalog_rejects_mismatched_array_lengths() {
+        Python::with_gil(|py| {
+            let canvas = Py::new(py, Canvas::new(16, 16)).unwrap();
+            let shape = Py::new(py, SpotShape(RsSpotShape::default())).unwrap();
+
+            pyo3::py_run!(
+                py,
+                canvas shape,
+                r#"
+try:
+    canvas.add_catalog([(8.0, 8.0), (1.0, 1.0)], [10.0], shape, 10.0)
+    assert False, "expected ValueError for mismatched magnitudes length"
+except ValueError:
+    pass
+
+try:
+    canvas.add_catalog([(8.0, 8.0)], [10.0], shape, 10.0, ebv=[0.1, 0.2])
+    assert False, "expected ValueError for mismatched ebv length"
+except ValueError:
+    pass
+"#
+            );
+        });
+    }
+
+    /// `.moffat()`/`.airy()` must construct without panicking, the
+    /// existing `scale`/`stretch`/`rotate` transform composition must
+    /// keep working on top of either profile, and `__repr__` must
+    /// report which profile and parameters are in use.
+    #[test]
+    fn moffat_and_airy_shapes_construct_and_compose_transforms() {
+        let default_shape = SpotShape(RsSpotShape::default());
+        let moffat_shape = SpotShape::moffat(2.5, 3.5);
+        let airy_shape = SpotShape::airy(4.25);
+
+        // Transform composition must still work on top of either profile.
+        let _ = moffat_shape.scale(2.0).stretch(1.5, 1.0).rotate(30.0);
+        let _ = airy_shape.scale(2.0).stretch(1.5, 1.0).rotate(30.0);
+
+        let default_repr = default_shape.__repr__();
+        let moffat_repr = moffat_shape.__repr__();
+        let airy_repr = airy_shape.__repr__();
+
+        assert_ne!(moffat_repr, default_repr);
+        assert_ne!(airy_repr, default_repr);
+        assert_ne!(moffat_repr, airy_repr);
+        assert!(moffat_repr.contains("2.5") && moffat_repr.contains("3.5"));
+        assert!(airy_repr.contains("4.25"));
+    }
+}